@@ -8,19 +8,35 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use chrono::{DateTime, Utc};
 use crate::protection::{ProtectionAction, Result, ProtectionError};
+use crate::protection::mod_log::{ActionContext, ModerationAuditLog};
 
 /// Action Executor trait
 #[async_trait]
 pub trait ActionExecutor: Send + Sync {
     /// 執行防護動作
     async fn execute(&self, action: &ProtectionAction) -> Result<()>;
-    
+
+    /// 執行防護動作並回傳動作 ID，供日後透過 `undo` 撤銷（計時制裁到期時則改用 `reverse`）
+    async fn execute_reversible(&self, action: &ProtectionAction) -> Result<String>;
+
+    /// 執行防護動作並產生管理員審計紀錄（觸發的 Violation、風險分數、jump link）
+    async fn execute_audited(&self, action: &ProtectionAction, context: ActionContext) -> Result<()>;
+
+    /// 取得此 Executor 使用的管理員審計日誌，供查詢與誤判回報
+    fn audit_log(&self) -> Arc<ModerationAuditLog>;
+
     /// 批次執行動作
     async fn execute_batch(&self, actions: Vec<ProtectionAction>) -> Result<Vec<ActionResult>>;
-    
+
     /// 撤銷動作（如果支援）
     async fn undo(&self, action_id: &str) -> Result<()>;
-    
+
+    /// 直接根據動作內容撤銷，不依賴 `history` 中的執行記錄
+    ///
+    /// 供 `ExpiryScheduler` 在到期時使用：計時制裁的 `ExpiryRecord` 本身已保存完整的
+    /// `ProtectionAction`，即使行程重啟導致 `history` 清空，仍可重建撤銷動作。
+    async fn reverse(&self, action: &ProtectionAction) -> Result<()>;
+
     /// 獲取執行歷史
     async fn get_history(&self, limit: usize) -> Result<Vec<ActionRecord>>;
 }
@@ -47,6 +63,7 @@ pub struct ActionRecord {
 pub struct DefaultActionExecutor {
     history: Arc<RwLock<Vec<ActionRecord>>>,
     max_history_size: usize,
+    audit_log: Arc<ModerationAuditLog>,
 }
 
 impl DefaultActionExecutor {
@@ -55,9 +72,19 @@ impl DefaultActionExecutor {
         Self {
             history: Arc::new(RwLock::new(Vec::new())),
             max_history_size: 1000,
+            audit_log: Arc::new(ModerationAuditLog::default()),
         }
     }
-    
+
+    /// 使用既有的管理員審計日誌創建 Action Executor（例如與 ProtectionManager 共用）
+    pub fn with_audit_log(audit_log: Arc<ModerationAuditLog>) -> Self {
+        Self {
+            history: Arc::new(RwLock::new(Vec::new())),
+            max_history_size: 1000,
+            audit_log,
+        }
+    }
+
     /// 生成動作 ID
     fn generate_action_id() -> String {
         uuid::Uuid::new_v4().to_string()
@@ -80,14 +107,12 @@ impl DefaultActionExecutor {
             history.drain(0..drain_count);
         }
     }
-}
 
-#[async_trait]
-impl ActionExecutor for DefaultActionExecutor {
-    async fn execute(&self, action: &ProtectionAction) -> Result<()> {
+    /// 執行動作並回傳其動作 ID，供 `execute` 與 `execute_reversible` 共用
+    async fn execute_and_record(&self, action: &ProtectionAction) -> Result<String> {
         let action_id = Self::generate_action_id();
         let start_time = Utc::now();
-        
+
         // 模擬執行動作（實際應整合 Discord API）
         let (success, error_message, can_undo) = match action {
             ProtectionAction::DeleteMessage { message_id, reason } => {
@@ -126,16 +151,37 @@ impl ActionExecutor for DefaultActionExecutor {
         
         // 記錄動作
         self.record_action(action.clone(), result, can_undo).await;
-        
+
         if success {
-            Ok(())
+            Ok(action_id)
         } else {
             Err(ProtectionError::ActionExecutionFailed(
                 error_message.unwrap_or_else(|| "未知錯誤".to_string())
             ))
         }
     }
-    
+}
+
+#[async_trait]
+impl ActionExecutor for DefaultActionExecutor {
+    async fn execute(&self, action: &ProtectionAction) -> Result<()> {
+        self.execute_and_record(action).await.map(|_| ())
+    }
+
+    async fn execute_reversible(&self, action: &ProtectionAction) -> Result<String> {
+        self.execute_and_record(action).await
+    }
+
+    async fn execute_audited(&self, action: &ProtectionAction, context: ActionContext) -> Result<()> {
+        self.execute(action).await?;
+        self.audit_log.record(action, context).await;
+        Ok(())
+    }
+
+    fn audit_log(&self) -> Arc<ModerationAuditLog> {
+        self.audit_log.clone()
+    }
+
     async fn execute_batch(&self, actions: Vec<ProtectionAction>) -> Result<Vec<ActionResult>> {
         let mut results = Vec::new();
         
@@ -167,23 +213,29 @@ impl ActionExecutor for DefaultActionExecutor {
     }
     
     async fn undo(&self, action_id: &str) -> Result<()> {
-        let history = self.history.read().await;
-        
-        let record = history
-            .iter()
-            .find(|r| r.id == action_id)
-            .ok_or_else(|| ProtectionError::ActionExecutionFailed(
-                format!("找不到動作記錄: {}", action_id)
-            ))?;
-        
+        let record = {
+            let history = self.history.read().await;
+            history
+                .iter()
+                .find(|r| r.id == action_id)
+                .cloned()
+                .ok_or_else(|| ProtectionError::ActionExecutionFailed(
+                    format!("找不到動作記錄: {}", action_id)
+                ))?
+        };
+
         if !record.can_undo {
             return Err(ProtectionError::ActionExecutionFailed(
                 "此動作無法撤銷".to_string()
             ));
         }
-        
+
+        self.reverse(&record.action).await
+    }
+
+    async fn reverse(&self, action: &ProtectionAction) -> Result<()> {
         // 模擬撤銷動作
-        match &record.action {
+        match action {
             ProtectionAction::Mute { user_id, .. } => {
                 tracing::info!("撤銷禁言: {}", user_id);
                 // TODO: 調用 Discord API 解除禁言
@@ -198,10 +250,10 @@ impl ActionExecutor for DefaultActionExecutor {
                 ));
             }
         }
-        
+
         Ok(())
     }
-    
+
     async fn get_history(&self, limit: usize) -> Result<Vec<ActionRecord>> {
         let history = self.history.read().await;
         let start = if history.len() > limit {
@@ -257,6 +309,31 @@ mod tests {
         assert!(results.iter().all(|r| r.success));
     }
     
+    #[tokio::test]
+    async fn test_execute_audited_records_mod_log_entry() {
+        let executor = DefaultActionExecutor::new();
+
+        let action = ProtectionAction::DeleteMessage {
+            message_id: "msg_1".to_string(),
+            reason: "垃圾訊息".to_string(),
+        };
+        let context = ActionContext {
+            guild_id: "guild_1".to_string(),
+            channel_id: "channel_1".to_string(),
+            message_id: "msg_1".to_string(),
+            violations: vec![],
+            risk_score: 0.9,
+            confidence: 0.8,
+            actor: "ActionExecutor".to_string(),
+        };
+
+        executor.execute_audited(&action, context).await.unwrap();
+
+        let entries = executor.audit_log().query("guild_1").await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].jump_link, "https://discord.com/channels/guild_1/channel_1/msg_1");
+    }
+
     #[tokio::test]
     async fn test_history() {
         let executor = DefaultActionExecutor::new();
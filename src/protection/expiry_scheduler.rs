@@ -0,0 +1,232 @@
+//! Expiry Scheduler
+//!
+//! 將一次性的定時防護動作（例如計時禁言）轉換為真正的暫時性制裁：
+//! 記錄到期時間、在逾時後自動撤銷，並在行程重啟後從儲存重新載入尚未到期的項目。
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use crate::protection::{ProtectionAction, Result, action_executor::ActionExecutor};
+
+/// 到期記錄 - 描述一個尚未撤銷的計時動作
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpiryRecord {
+    /// 對應 ActionExecutor 歷史中的動作 ID，也是此記錄在 `ExpiryStore` 中的鍵
+    pub action_id: String,
+    pub guild_id: String,
+    pub user_id: String,
+    pub action: ProtectionAction,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// 到期記錄儲存介面，方便未來替換為資料庫實作
+#[async_trait]
+pub trait ExpiryStore: Send + Sync {
+    /// 保存一筆到期記錄
+    async fn save(&self, record: ExpiryRecord) -> Result<()>;
+
+    /// 移除一筆已處理的到期記錄
+    async fn remove(&self, action_id: &str) -> Result<()>;
+
+    /// 載入指定群組尚未撤銷的記錄（不論 `expires_at` 是否已過）
+    async fn load_for_guild(&self, guild_id: &str) -> Result<Vec<ExpiryRecord>>;
+
+    /// 載入所有尚未撤銷的記錄（不論 `expires_at` 是否已過；行程重啟後使用）
+    async fn load_all(&self) -> Result<Vec<ExpiryRecord>>;
+}
+
+/// 預設的記憶體到期記錄儲存
+#[derive(Default)]
+pub struct InMemoryExpiryStore {
+    records: RwLock<HashMap<String, ExpiryRecord>>,
+}
+
+impl InMemoryExpiryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ExpiryStore for InMemoryExpiryStore {
+    async fn save(&self, record: ExpiryRecord) -> Result<()> {
+        self.records.write().await.insert(record.action_id.clone(), record);
+        Ok(())
+    }
+
+    async fn remove(&self, action_id: &str) -> Result<()> {
+        self.records.write().await.remove(action_id);
+        Ok(())
+    }
+
+    async fn load_for_guild(&self, guild_id: &str) -> Result<Vec<ExpiryRecord>> {
+        Ok(self.records
+            .read()
+            .await
+            .values()
+            .filter(|r| r.guild_id == guild_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn load_all(&self) -> Result<Vec<ExpiryRecord>> {
+        Ok(self.records.read().await.values().cloned().collect())
+    }
+}
+
+/// Expiry Scheduler - 由 ProtectionManager 持有，負責計時動作的到期撤銷
+pub struct ExpiryScheduler {
+    store: Arc<dyn ExpiryStore>,
+    action_executor: Arc<dyn ActionExecutor>,
+}
+
+impl ExpiryScheduler {
+    /// 創建新的 Expiry Scheduler
+    pub fn new(store: Arc<dyn ExpiryStore>, action_executor: Arc<dyn ActionExecutor>) -> Self {
+        Self { store, action_executor }
+    }
+
+    /// 若動作帶有持續時間（目前為 `Mute`），排程到期後的自動撤銷
+    ///
+    /// 非計時動作會被忽略，呼叫方應照常透過 `ActionExecutor::execute` 執行。
+    pub async fn schedule_if_timed(&self, guild_id: &str, action: &ProtectionAction) -> Result<()> {
+        let duration_seconds = match action {
+            ProtectionAction::Mute { duration_seconds, .. } => *duration_seconds,
+            _ => return Ok(()),
+        };
+
+        let action_id = self.action_executor.execute_reversible(action).await?;
+        let record = ExpiryRecord {
+            action_id: action_id.clone(),
+            guild_id: guild_id.to_string(),
+            user_id: Self::target_user_id(action).to_string(),
+            action: action.clone(),
+            expires_at: Utc::now() + ChronoDuration::seconds(duration_seconds as i64),
+        };
+
+        self.store.save(record.clone()).await?;
+        self.arm_timer(record);
+
+        Ok(())
+    }
+
+    /// 從儲存重新載入尚未撤銷的記錄並重新排程計時器（已過期者立即撤銷）
+    ///
+    /// 應在 `ProtectionManager::initialize` 中呼叫一次，確保行程重啟後
+    /// 既有的暫時性制裁仍會在原訂時間撤銷。
+    pub async fn reload_pending(&self) -> Result<()> {
+        for record in self.store.load_all().await? {
+            self.arm_timer(record);
+        }
+        Ok(())
+    }
+
+    /// 查詢某群組目前仍在等待到期的制裁
+    pub async fn pending_expirations(&self, guild_id: &str) -> Result<Vec<ExpiryRecord>> {
+        self.store.load_for_guild(guild_id).await
+    }
+
+    /// 安排一個計時器，到期後直接根據持久化的 `ExpiryRecord` 撤銷對應動作並清除記錄
+    ///
+    /// 到期時間以 `record.expires_at` 為準計算剩餘秒數，因此無論是剛排程的動作還是
+    /// 重啟後從儲存重新載入的記錄都能共用同一套邏輯。撤銷呼叫 `ActionExecutor::reverse`
+    /// 而非 `undo`，因為重啟後 `ActionExecutor` 的執行歷史已清空，只有持久化的
+    /// `ExpiryRecord::action` 仍保有重建撤銷動作所需的資訊。
+    fn arm_timer(&self, record: ExpiryRecord) {
+        let store = self.store.clone();
+        let action_executor = self.action_executor.clone();
+        let remaining = (record.expires_at - Utc::now()).num_seconds().max(0) as u64;
+
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_secs(remaining)).await;
+
+            if let Err(e) = action_executor.reverse(&record.action).await {
+                tracing::warn!("撤銷到期制裁失敗 {}: {}", record.action_id, e);
+            }
+
+            if let Err(e) = store.remove(&record.action_id).await {
+                tracing::warn!("移除到期記錄失敗 {}: {}", record.action_id, e);
+            }
+        });
+    }
+
+    fn target_user_id(action: &ProtectionAction) -> &str {
+        match action {
+            ProtectionAction::Mute { user_id, .. }
+            | ProtectionAction::Ban { user_id, .. }
+            | ProtectionAction::Warn { user_id, .. }
+            | ProtectionAction::Kick { user_id, .. } => user_id,
+            ProtectionAction::DeleteMessage { message_id, .. } => message_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protection::action_executor::DefaultActionExecutor;
+
+    #[tokio::test]
+    async fn test_schedule_ignores_non_timed_actions() {
+        let store: Arc<dyn ExpiryStore> = Arc::new(InMemoryExpiryStore::new());
+        let executor: Arc<dyn ActionExecutor> = Arc::new(DefaultActionExecutor::new());
+        let scheduler = ExpiryScheduler::new(store, executor);
+
+        let action = ProtectionAction::Warn {
+            user_id: "user_1".to_string(),
+            reason: "測試".to_string(),
+        };
+        scheduler.schedule_if_timed("guild_1", &action).await.unwrap();
+
+        assert!(scheduler.pending_expirations("guild_1").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_schedule_persists_timed_mute() {
+        let store: Arc<dyn ExpiryStore> = Arc::new(InMemoryExpiryStore::new());
+        let executor: Arc<dyn ActionExecutor> = Arc::new(DefaultActionExecutor::new());
+        let scheduler = ExpiryScheduler::new(store, executor);
+
+        let action = ProtectionAction::Mute {
+            user_id: "user_1".to_string(),
+            duration_seconds: 3600,
+            reason: "洗版".to_string(),
+        };
+        scheduler.schedule_if_timed("guild_1", &action).await.unwrap();
+
+        let pending = scheduler.pending_expirations("guild_1").await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].user_id, "user_1");
+    }
+
+    #[tokio::test]
+    async fn test_reload_pending_rearms_timers() {
+        let store: Arc<dyn ExpiryStore> = Arc::new(InMemoryExpiryStore::new());
+        let executor: Arc<dyn ActionExecutor> = Arc::new(DefaultActionExecutor::new());
+
+        store.save(ExpiryRecord {
+            action_id: "existing_action".to_string(),
+            guild_id: "guild_1".to_string(),
+            user_id: "user_2".to_string(),
+            action: ProtectionAction::Mute {
+                user_id: "user_2".to_string(),
+                duration_seconds: 1,
+                reason: "重啟前已存在".to_string(),
+            },
+            expires_at: Utc::now() + ChronoDuration::seconds(1),
+        }).await.unwrap();
+
+        let scheduler = ExpiryScheduler::new(store, executor);
+        scheduler.reload_pending().await.unwrap();
+
+        let pending = scheduler.pending_expirations("guild_1").await.unwrap();
+        assert_eq!(pending.len(), 1);
+
+        // 重新排程的計時器到期後撤銷動作並清除記錄
+        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+        assert!(scheduler.pending_expirations("guild_1").await.unwrap().is_empty());
+    }
+}
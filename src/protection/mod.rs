@@ -22,12 +22,16 @@ pub mod inspector;
 pub mod rules_engine;
 pub mod pattern_recognition;
 pub mod action_executor;
+pub mod expiry_scheduler;
+pub mod mod_log;
 
 // Re-exports
 pub use inspector::{MessageInspector, InspectorConfig};
 pub use rules_engine::{RulesEngine, RuleDecision};
 pub use pattern_recognition::{PatternRecognizer, SpamScore, SafetyResult};
 pub use action_executor::{ActionExecutor};
+pub use expiry_scheduler::{ExpiryScheduler, ExpiryStore, ExpiryRecord, InMemoryExpiryStore};
+pub use mod_log::{ModerationAuditLog, ModerationLogEntry, ActionContext};
 
 /// Protection 模組錯誤類型
 #[derive(Error, Debug)]
@@ -149,8 +153,17 @@ pub trait ProtectionService: Send + Sync {
     /// 檢測訊息
     async fn inspect_message(&self, context: &MessageContext) -> Result<InspectionResult>;
     
-    /// 執行防護動作
-    async fn execute_action(&self, action: &ProtectionAction) -> Result<()>;
+    /// 執行防護動作，並為其產生管理員審計紀錄
+    ///
+    /// 這是整個防護系統唯一的動作執行入口：計時的 `Mute` 會同時交由 expiry scheduler
+    /// 排程到期撤銷並寫入審計日誌，其餘動作則直接執行並審計，確保每一次執行都留下
+    /// 可追溯的記錄。
+    async fn execute_action(
+        &self,
+        context: &MessageContext,
+        inspection: &InspectionResult,
+        action: &ProtectionAction,
+    ) -> Result<()>;
     
     /// 更新防護等級
     async fn update_protection_level(&self, guild_id: &str, level: ProtectionLevel) -> Result<()>;
@@ -177,38 +190,122 @@ pub struct ProtectionManager {
     rules_engine: Arc<RwLock<dyn RulesEngine>>,
     pattern_recognizer: Arc<dyn PatternRecognizer>,
     action_executor: Arc<dyn ActionExecutor>,
+    expiry_scheduler: Arc<ExpiryScheduler>,
     statistics: Arc<RwLock<dashmap::DashMap<String, ProtectionStatistics>>>,
 }
 
 impl ProtectionManager {
     /// 創建新的 Protection Manager
+    ///
+    /// 到期記錄預設保存在記憶體中，行程重啟後尚未到期的計時制裁記錄會遺失。
+    /// 正式環境若需要制裁確實在重啟後仍準時撤銷，請改用 [`Self::with_expiry_store`]
+    /// 注入資料庫等持久化的 `ExpiryStore` 實作。
     pub fn new(
         inspector: Arc<dyn MessageInspector>,
         rules_engine: Arc<RwLock<dyn RulesEngine>>,
         pattern_recognizer: Arc<dyn PatternRecognizer>,
         action_executor: Arc<dyn ActionExecutor>,
     ) -> Self {
+        Self::with_expiry_store(
+            inspector,
+            rules_engine,
+            pattern_recognizer,
+            action_executor,
+            Arc::new(expiry_scheduler::InMemoryExpiryStore::new()),
+        )
+    }
+
+    /// 創建新的 Protection Manager，並指定到期記錄的儲存方式
+    ///
+    /// 供正式環境注入持久化的 `ExpiryStore`（例如資料庫實作），確保計時制裁在行程
+    /// 重啟後仍能被 `initialize` 的 `reload_pending` 重新排程撤銷。
+    pub fn with_expiry_store(
+        inspector: Arc<dyn MessageInspector>,
+        rules_engine: Arc<RwLock<dyn RulesEngine>>,
+        pattern_recognizer: Arc<dyn PatternRecognizer>,
+        action_executor: Arc<dyn ActionExecutor>,
+        expiry_store: Arc<dyn ExpiryStore>,
+    ) -> Self {
+        let expiry_scheduler = Arc::new(ExpiryScheduler::new(expiry_store, action_executor.clone()));
+
         Self {
             inspector,
             rules_engine,
             pattern_recognizer,
             action_executor,
+            expiry_scheduler,
             statistics: Arc::new(RwLock::new(dashmap::DashMap::new())),
         }
     }
-    
+
     /// 初始化防護系統
     pub async fn initialize(&self) -> Result<()> {
         // 初始化各個組件
         tracing::info!("初始化群組防護系統");
+
+        // 重新載入尚未到期的計時制裁，確保行程重啟後仍會準時撤銷
+        self.expiry_scheduler.reload_pending().await?;
+
         Ok(())
     }
-    
+
     /// 關閉防護系統
     pub async fn shutdown(&self) -> Result<()> {
         tracing::info!("關閉群組防護系統");
         Ok(())
     }
+
+    /// 查詢指定群組目前仍在等待到期的計時制裁
+    pub async fn pending_expirations(&self, guild_id: &str) -> Result<Vec<ExpiryRecord>> {
+        self.expiry_scheduler.pending_expirations(guild_id).await
+    }
+
+    /// 執行一次檢測結果建議的所有動作，每個動作都透過 `execute_action` 執行，
+    /// 因此計時動作會被排程到期撤銷，且全部動作都會產生管理員審計紀錄
+    pub async fn execute_suggested_actions(
+        &self,
+        context: &MessageContext,
+        inspection: &InspectionResult,
+    ) -> Result<()> {
+        for action in &inspection.suggested_actions {
+            self.execute_action(context, inspection, action).await?;
+        }
+        Ok(())
+    }
+
+    /// 設置群組的管理員記錄頻道
+    pub async fn set_mod_log_channel(&self, guild_id: &str, channel_id: String) {
+        self.action_executor.audit_log().set_mod_log_channel(guild_id, channel_id).await;
+    }
+
+    /// 查詢群組的管理員審計紀錄
+    pub async fn mod_log(&self, guild_id: &str) -> Vec<ModerationLogEntry> {
+        self.action_executor.audit_log().query(guild_id).await
+    }
+
+    /// 將一筆審計紀錄標記為誤判，並回饋到該群組的 `false_positives` 統計
+    pub async fn mark_false_positive(&self, guild_id: &str, entry_id: &str) -> Result<()> {
+        let entry = self.action_executor.audit_log().mark_false_positive(guild_id, entry_id).await
+            .ok_or_else(|| ProtectionError::ConfigurationError(
+                format!("找不到審計紀錄: {}", entry_id)
+            ))?;
+        let _ = entry;
+
+        let stats = self.statistics.read().await;
+        let mut stat_entry = stats.entry(guild_id.to_string()).or_insert_with(|| ProtectionStatistics {
+            guild_id: guild_id.to_string(),
+            total_messages_inspected: 0,
+            violations_detected: 0,
+            actions_taken: 0,
+            false_positives: 0,
+            current_protection_level: ProtectionLevel::Medium,
+            last_update: Utc::now(),
+        });
+        stat_entry.false_positives += 1;
+        stat_entry.last_update = Utc::now();
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -218,11 +315,34 @@ impl ProtectionService for ProtectionManager {
         self.inspector.inspect(context).await
     }
     
-    async fn execute_action(&self, action: &ProtectionAction) -> Result<()> {
-        // 使用 action_executor 執行防護動作
-        self.action_executor.execute(action).await
+    async fn execute_action(
+        &self,
+        context: &MessageContext,
+        inspection: &InspectionResult,
+        action: &ProtectionAction,
+    ) -> Result<()> {
+        let action_context = ActionContext {
+            guild_id: context.message.guild_id.clone(),
+            channel_id: context.message.channel_id.clone(),
+            message_id: inspection.message_id.clone(),
+            violations: inspection.violations.clone(),
+            risk_score: inspection.risk_score,
+            confidence: inspection.confidence,
+            actor: "ActionExecutor".to_string(),
+        };
+
+        if let ProtectionAction::Mute { .. } = action {
+            // 計時動作交由 expiry scheduler 執行並排程到期撤銷，並同時寫入審計日誌，
+            // 使計時制裁既會準時撤銷、也留下可追溯的紀錄
+            self.expiry_scheduler.schedule_if_timed(&action_context.guild_id, action).await?;
+            self.action_executor.audit_log().record(action, action_context).await;
+            return Ok(());
+        }
+
+        // 其他動作直接執行並產生審計紀錄
+        self.action_executor.execute_audited(action, action_context).await
     }
-    
+
     async fn update_protection_level(&self, guild_id: &str, level: ProtectionLevel) -> Result<()> {
         // 更新規則引擎的防護等級
         let mut engine = self.rules_engine.write().await;
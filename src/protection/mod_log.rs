@@ -0,0 +1,215 @@
+//! Moderation Audit Log
+//!
+//! 每次透過 `ProtectionManager::execute_action` 或 `ActionExecutor::execute_audited`
+//! 執行防護動作時產生一筆結構化的審計紀錄（單獨呼叫 `ActionExecutor::execute`、
+//! `execute_reversible` 或 `execute_batch` 不會產生紀錄），內容包含
+//! 觸發的 `Violation`、`InspectionResult` 的 `risk_score`/`confidence`、
+//! 執行元件，以及指向違規訊息的 Discord jump link。紀錄會轉送到可配置的
+//! 管理員記錄頻道，並以每群組的環狀緩衝區保留，供誤判回報（false positive
+//! triage）查詢使用。
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use tokio::sync::RwLock;
+use crate::protection::{ProtectionAction, Violation};
+
+/// 執行動作時的上下文，用於組出審計紀錄
+#[derive(Debug, Clone)]
+pub struct ActionContext {
+    pub guild_id: String,
+    pub channel_id: String,
+    pub message_id: String,
+    pub violations: Vec<Violation>,
+    pub risk_score: f32,
+    pub confidence: f32,
+    pub actor: String,
+}
+
+impl ActionContext {
+    /// 組出指向違規訊息的 Discord jump link
+    fn jump_link(&self) -> String {
+        format!(
+            "https://discord.com/channels/{}/{}/{}",
+            self.guild_id, self.channel_id, self.message_id
+        )
+    }
+}
+
+/// 一筆審計紀錄
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModerationLogEntry {
+    pub id: String,
+    pub guild_id: String,
+    pub action: ProtectionAction,
+    pub violations: Vec<Violation>,
+    pub risk_score: f32,
+    pub confidence: f32,
+    pub actor: String,
+    pub jump_link: String,
+    pub created_at: DateTime<Utc>,
+    pub false_positive: bool,
+}
+
+/// 管理員記錄日誌 - 每群組環狀緩衝區 + 可配置的記錄頻道
+pub struct ModerationAuditLog {
+    entries: RwLock<HashMap<String, VecDeque<ModerationLogEntry>>>,
+    mod_log_channels: RwLock<HashMap<String, String>>,
+    max_entries_per_guild: usize,
+}
+
+impl ModerationAuditLog {
+    /// 創建新的管理員記錄日誌
+    pub fn new(max_entries_per_guild: usize) -> Self {
+        Self {
+            entries: RwLock::new(HashMap::new()),
+            mod_log_channels: RwLock::new(HashMap::new()),
+            max_entries_per_guild,
+        }
+    }
+
+    /// 設置群組的管理員記錄頻道
+    pub async fn set_mod_log_channel(&self, guild_id: &str, channel_id: String) {
+        self.mod_log_channels.write().await.insert(guild_id.to_string(), channel_id);
+    }
+
+    /// 記錄一次執行的防護動作
+    pub async fn record(&self, action: &ProtectionAction, context: ActionContext) -> ModerationLogEntry {
+        let entry = ModerationLogEntry {
+            id: uuid::Uuid::new_v4().to_string(),
+            guild_id: context.guild_id.clone(),
+            action: action.clone(),
+            violations: context.violations.clone(),
+            risk_score: context.risk_score,
+            confidence: context.confidence,
+            actor: context.actor.clone(),
+            jump_link: context.jump_link(),
+            created_at: Utc::now(),
+            false_positive: false,
+        };
+
+        // 轉送到管理員記錄頻道
+        let channel = self.mod_log_channels.read().await.get(&context.guild_id).cloned();
+        match channel {
+            Some(channel_id) => {
+                tracing::info!(
+                    "MODLOG #{}: {} 對 {:?} 執行 {:?} (risk={:.2}, confidence={:.2}) - {}",
+                    channel_id, entry.actor, entry.violations, entry.action,
+                    entry.risk_score, entry.confidence, entry.jump_link
+                );
+                // TODO: 調用 Discord API 發送訊息到 channel_id
+            },
+            None => {
+                tracing::info!(
+                    "MODLOG (未設置記錄頻道) {}: {:?} - {}",
+                    entry.actor, entry.action, entry.jump_link
+                );
+            }
+        }
+
+        let mut entries = self.entries.write().await;
+        let guild_entries = entries.entry(context.guild_id).or_default();
+        guild_entries.push_back(entry.clone());
+        if guild_entries.len() > self.max_entries_per_guild {
+            guild_entries.pop_front();
+        }
+
+        entry
+    }
+
+    /// 查詢某群組的審計紀錄
+    pub async fn query(&self, guild_id: &str) -> Vec<ModerationLogEntry> {
+        self.entries
+            .read()
+            .await
+            .get(guild_id)
+            .map(|entries| entries.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// 將一筆紀錄標記為誤判，回傳標記後的紀錄供呼叫方回饋統計資料
+    pub async fn mark_false_positive(&self, guild_id: &str, entry_id: &str) -> Option<ModerationLogEntry> {
+        let mut entries = self.entries.write().await;
+        let guild_entries = entries.get_mut(guild_id)?;
+        let entry = guild_entries.iter_mut().find(|e| e.id == entry_id)?;
+        entry.false_positive = true;
+        Some(entry.clone())
+    }
+}
+
+impl Default for ModerationAuditLog {
+    fn default() -> Self {
+        Self::new(500)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protection::{Severity, ViolationType};
+
+    fn sample_context() -> ActionContext {
+        ActionContext {
+            guild_id: "guild_1".to_string(),
+            channel_id: "channel_1".to_string(),
+            message_id: "message_1".to_string(),
+            violations: vec![Violation {
+                violation_type: ViolationType::Spam,
+                severity: Severity::High,
+                description: "垃圾訊息檢測".to_string(),
+                evidence: "score=0.9".to_string(),
+            }],
+            risk_score: 0.9,
+            confidence: 0.85,
+            actor: "ActionExecutor".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_builds_jump_link() {
+        let log = ModerationAuditLog::new(10);
+        let action = ProtectionAction::DeleteMessage {
+            message_id: "message_1".to_string(),
+            reason: "垃圾訊息".to_string(),
+        };
+
+        let entry = log.record(&action, sample_context()).await;
+        assert_eq!(entry.jump_link, "https://discord.com/channels/guild_1/channel_1/message_1");
+
+        let queried = log.query("guild_1").await;
+        assert_eq!(queried.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_ring_buffer_trims_oldest() {
+        let log = ModerationAuditLog::new(2);
+        let action = ProtectionAction::Warn {
+            user_id: "user_1".to_string(),
+            reason: "測試".to_string(),
+        };
+
+        for i in 0..5 {
+            let mut context = sample_context();
+            context.message_id = format!("message_{}", i);
+            log.record(&action, context).await;
+        }
+
+        let entries = log.query("guild_1").await;
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].jump_link.ends_with("/message_3"));
+        assert!(entries[1].jump_link.ends_with("/message_4"));
+    }
+
+    #[tokio::test]
+    async fn test_mark_false_positive() {
+        let log = ModerationAuditLog::new(10);
+        let action = ProtectionAction::Warn {
+            user_id: "user_1".to_string(),
+            reason: "測試".to_string(),
+        };
+        let entry = log.record(&action, sample_context()).await;
+
+        let marked = log.mark_false_positive("guild_1", &entry.id).await.unwrap();
+        assert!(marked.false_positive);
+    }
+}
@@ -6,6 +6,8 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use regex::Regex;
 use chrono::{DateTime, Utc, Duration};
 use crate::protection::{Message, ViolationType};
@@ -81,6 +83,8 @@ pub struct DefaultPatternRecognizer {
     unsafe_domains: HashSet<String>,
     url_shorteners: HashSet<String>,
     emoji_spam_patterns: Vec<Regex>,
+    /// 兩則訊息 SimHash 的漢明距離在此範圍內視為近似重複
+    near_duplicate_threshold: u32,
 }
 
 impl DefaultPatternRecognizer {
@@ -133,6 +137,7 @@ impl DefaultPatternRecognizer {
             unsafe_domains,
             url_shorteners,
             emoji_spam_patterns,
+            near_duplicate_threshold: 3,
         }
     }
     
@@ -173,6 +178,79 @@ impl DefaultPatternRecognizer {
         1.0 - (distance as f32 / max_len)
     }
     
+    /// 正規化訊息內容供切詞使用：轉小寫、移除零寬字元與 emoji、壓縮空白
+    fn normalize_for_shingling(content: &str) -> String {
+        let stripped: String = content
+            .chars()
+            .filter(|c| !matches!(c, '\u{200B}'..='\u{200D}' | '\u{FEFF}'))
+            .filter(|c| !matches!(c, '\u{1F000}'..='\u{1FAFF}' | '\u{2600}'..='\u{27BF}' | '\u{FE0F}'))
+            .collect();
+
+        stripped
+            .to_lowercase()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// 將正規化後的內容切成重疊的詞組（2-gram）；過短則退回字元 2-gram
+    fn shingles(normalized: &str) -> Vec<String> {
+        let tokens: Vec<&str> = normalized.split(' ').filter(|t| !t.is_empty()).collect();
+
+        if tokens.len() >= 2 {
+            tokens.windows(2).map(|w| format!("{} {}", w[0], w[1])).collect()
+        } else {
+            let chars: Vec<char> = normalized.chars().collect();
+            if chars.len() >= 2 {
+                chars.windows(2).map(|w| w.iter().collect()).collect()
+            } else {
+                vec![normalized.to_string()]
+            }
+        }
+    }
+
+    /// 將單一 shingle 雜湊為 64 位元
+    fn hash_shingle(shingle: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        shingle.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// 計算訊息內容的 64 位元 SimHash，用於近似重複比對
+    ///
+    /// 對每個（依出現頻率加權的）shingle 雜湊值逐位元累加：位元為 1 加權重、
+    /// 為 0 減權重，最後依累加器正負決定輸出位元。
+    fn simhash(&self, content: &str) -> u64 {
+        let normalized = Self::normalize_for_shingling(content);
+        let shingles = Self::shingles(&normalized);
+
+        let mut frequencies: HashMap<String, i64> = HashMap::new();
+        for shingle in shingles {
+            *frequencies.entry(shingle).or_insert(0) += 1;
+        }
+
+        let mut accumulator = [0i64; 64];
+        for (shingle, weight) in frequencies {
+            let hash = Self::hash_shingle(&shingle);
+            for (bit, acc) in accumulator.iter_mut().enumerate() {
+                if (hash >> bit) & 1 == 1 {
+                    *acc += weight;
+                } else {
+                    *acc -= weight;
+                }
+            }
+        }
+
+        accumulator.iter().enumerate().fold(0u64, |hash, (bit, &acc)| {
+            if acc > 0 { hash | (1 << bit) } else { hash }
+        })
+    }
+
+    /// 計算兩個 SimHash 的漢明距離
+    fn hamming_distance(a: u64, b: u64) -> u32 {
+        (a ^ b).count_ones()
+    }
+
     /// 從 URL 提取域名
     fn extract_domain(&self, url: &str) -> Option<String> {
         if let Ok(parsed) = url::Url::parse(url) {
@@ -301,7 +379,33 @@ impl PatternRecognizer for DefaultPatternRecognizer {
                 max_similarity = max_similarity.max(similarity);
             }
         }
-        
+
+        // 近似重複偵測（SimHash）：零寬字元與 emoji 填充在正規化時移除，不影響比對；
+        // 詞序調換會改變 2-gram shingle，漢明距離不保證落在門檻內
+        let simhashes: Vec<u64> = messages.iter().map(|m| self.simhash(&m.content)).collect();
+        let mut near_duplicate_cluster_sizes = vec![1usize; simhashes.len()];
+        let mut closest_near_duplicate_distance: Option<u32> = None;
+
+        for i in 0..simhashes.len() {
+            for j in i + 1..simhashes.len() {
+                let distance = Self::hamming_distance(simhashes[i], simhashes[j]);
+                if distance <= self.near_duplicate_threshold {
+                    near_duplicate_cluster_sizes[i] += 1;
+                    near_duplicate_cluster_sizes[j] += 1;
+                    closest_near_duplicate_distance = Some(
+                        closest_near_duplicate_distance.map_or(distance, |d| d.min(distance))
+                    );
+                }
+            }
+        }
+
+        if let Some(max_cluster) = near_duplicate_cluster_sizes.into_iter().max() {
+            duplicate_count = duplicate_count.max(max_cluster);
+        }
+        if let Some(distance) = closest_near_duplicate_distance {
+            max_similarity = max_similarity.max(1.0 - (distance as f32 / 64.0));
+        }
+
         Ok(DuplicateDetectionResult {
             has_duplicates: duplicate_count > 1 || max_similarity > 0.8,
             duplicate_count,
@@ -479,4 +583,73 @@ mod tests {
         assert!(recognizer.similarity_score("hello", "hallo") > 0.7);
         assert!(recognizer.similarity_score("hello", "world") < 0.3);
     }
+
+    #[tokio::test]
+    async fn test_simhash_normalizes_zero_width_padding_to_identical_hash() {
+        let recognizer = DefaultPatternRecognizer::new();
+
+        let original = recognizer.simhash("buy crypto now limited offer click here");
+        // 常見的過濾器規避手法：在詞間插入零寬字元
+        let zero_width_padded = recognizer.simhash("buy\u{200B} crypto now\u{200C} limited offer click here");
+
+        assert_eq!(original, zero_width_padded);
+    }
+
+    #[tokio::test]
+    async fn test_simhash_is_closer_for_near_duplicates_than_unrelated_text() {
+        let recognizer = DefaultPatternRecognizer::new();
+
+        let original = recognizer.simhash(
+            "limited time offer click here to claim your free prize now before it expires today"
+        );
+        // 詞序調換
+        let swapped = recognizer.simhash(
+            "time limited offer click here to claim your free prize now before it expires today"
+        );
+        let unrelated = recognizer.simhash(
+            "my cat sat quietly near the window watching birds outside all afternoon long"
+        );
+
+        assert!(
+            DefaultPatternRecognizer::hamming_distance(original, swapped)
+                < DefaultPatternRecognizer::hamming_distance(original, unrelated)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simhash_falls_back_to_character_shingles_for_short_messages() {
+        let recognizer = DefaultPatternRecognizer::new();
+        let a = recognizer.simhash("hi");
+        let b = recognizer.simhash("hi");
+        assert_eq!(a, b);
+        // 單一詞的訊息退回字元 2-gram
+        assert_eq!(DefaultPatternRecognizer::shingles("spam"), vec!["sp", "pa", "am"]);
+    }
+
+    #[tokio::test]
+    async fn test_detect_duplicates_flags_near_duplicate_spam() {
+        let recognizer = DefaultPatternRecognizer::new();
+        let messages: Vec<Message> = vec![
+            "buy crypto now limited offer click here",
+            "buy\u{200B} crypto now\u{200C} limited offer click here",
+            "buy\u{200D} crypto now limited\u{FEFF} offer click here",
+        ]
+        .into_iter()
+        .map(|content| Message {
+            id: uuid::Uuid::new_v4().to_string(),
+            author_id: "spammer".to_string(),
+            guild_id: "guild_1".to_string(),
+            channel_id: "channel_1".to_string(),
+            content: content.to_string(),
+            timestamp: Utc::now(),
+            attachments: vec![],
+            embeds: vec![],
+            mentions: vec![],
+        })
+        .collect();
+
+        let result = recognizer.detect_duplicates(&messages).await.unwrap();
+        assert!(result.has_duplicates);
+        assert!(result.duplicate_count >= 3);
+    }
 }